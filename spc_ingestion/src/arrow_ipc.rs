@@ -0,0 +1,139 @@
+// Arrow IPC read/write for columnar policy tables: zero-copy,
+// language-interoperable storage for policy rows (id, normalized text,
+// BLAKE3 hash).
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow::array::{Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+
+/// Column names, in schema order, for the canonical policy-row IPC table.
+const COLUMNS: &[&str] = &["id", "normalized_text", "blake3_hash"];
+
+fn schema() -> Schema {
+    Schema::new(
+        COLUMNS
+            .iter()
+            .map(|name| Field::new(*name, DataType::Utf8, false))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Write `columns` (one `Vec<String>` per [`COLUMNS`] entry, all the same
+/// length) to `path` as a single-batch Arrow IPC file.
+pub fn write_arrow_ipc(columns: &HashMap<String, Vec<String>>, path: &str) -> Result<(), String> {
+    let schema = schema();
+
+    let arrays = COLUMNS
+        .iter()
+        .map(|name| {
+            let values = columns
+                .get(*name)
+                .ok_or_else(|| format!("Missing column: {name}"))?;
+            Ok(Arc::new(StringArray::from(values.clone())) as Arc<dyn Array>)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), arrays)
+        .map_err(|e| format!("Failed to build record batch: {e}"))?;
+
+    let file = File::create(path).map_err(|e| format!("Failed to create {path}: {e}"))?;
+    let mut writer = FileWriter::try_new(file, &schema)
+        .map_err(|e| format!("Failed to open IPC writer: {e}"))?;
+    writer
+        .write(&batch)
+        .map_err(|e| format!("Failed to write record batch: {e}"))?;
+    writer
+        .finish()
+        .map_err(|e| format!("Failed to finalize IPC file: {e}"))
+}
+
+/// Read the policy-row record batch back from the Arrow IPC file at `path`.
+pub fn read_arrow_ipc(path: &str) -> Result<HashMap<String, Vec<String>>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {path}: {e}"))?;
+    let mut reader =
+        FileReader::try_new(file, None).map_err(|e| format!("Failed to open IPC reader: {e}"))?;
+
+    let batch = reader
+        .next()
+        .ok_or_else(|| "IPC file contains no record batches".to_string())?
+        .map_err(|e| format!("Failed to read record batch: {e}"))?;
+
+    COLUMNS
+        .iter()
+        .map(|name| {
+            let array = batch
+                .column_by_name(name)
+                .ok_or_else(|| format!("Missing column: {name}"))?;
+            let strings = array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .ok_or_else(|| format!("Column {name} is not Utf8"))?
+                .iter()
+                .map(|v| v.unwrap_or_default().to_string())
+                .collect();
+            Ok(((*name).to_string(), strings))
+        })
+        .collect()
+}
+
+/// Hash the raw Arrow IPC bytes at `path` with BLAKE3, so the same
+/// columnar dataset always hashes identically.
+pub fn hash_arrow_batch(path: &str) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {path}: {e}"))?;
+    Ok(blake3::hash(&bytes).to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("spc_ingestion_{name}_{}.arrow", std::process::id()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[test]
+    fn test_write_then_read_round_trips_columns() {
+        let path = temp_path("round_trip");
+        let mut columns = HashMap::new();
+        columns.insert("id".to_string(), vec!["p1".to_string(), "p2".to_string()]);
+        columns.insert(
+            "normalized_text".to_string(),
+            vec!["policy one".to_string(), "policy two".to_string()],
+        );
+        columns.insert(
+            "blake3_hash".to_string(),
+            vec!["aaaa".to_string(), "bbbb".to_string()],
+        );
+
+        write_arrow_ipc(&columns, &path).unwrap();
+        let read_back = read_arrow_ipc(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read_back, columns);
+    }
+
+    #[test]
+    fn test_hash_arrow_batch_is_deterministic() {
+        let path = temp_path("hash_batch");
+        let mut columns = HashMap::new();
+        columns.insert("id".to_string(), vec!["p1".to_string()]);
+        columns.insert("normalized_text".to_string(), vec!["policy".to_string()]);
+        columns.insert("blake3_hash".to_string(), vec!["aaaa".to_string()]);
+
+        write_arrow_ipc(&columns, &path).unwrap();
+        let first = hash_arrow_batch(&path).unwrap();
+        let second = hash_arrow_batch(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,150 @@
+// Homoglyph / confusable canonicalization.
+//
+// Policy text hashed for canonical integrity can be spoofed by swapping in
+// visually-identical characters from another script (e.g. Cyrillic "а" for
+// Latin "a"), which changes the BLAKE3 digest without changing how the text
+// looks to a reviewer. This module detects the dominant script of a string
+// and folds out-of-script confusables back into it before hashing, using
+// Unicode's own `Script` property (UAX #24) and `confusables.txt` /
+// UTS #39 skeleton mapping rather than a hand-rolled lookalike table, so it
+// covers digits, fullwidth/mathematical variants, and every script the
+// Unicode Consortium tracks as confusable -- not just Latin/Cyrillic/Greek.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::confusable_detection::skeleton;
+
+/// Upper bound (exclusive) of the code point range scanned to build
+/// [`skeleton_index`]: every assignable Unicode code point, so no script
+/// (however obscure or however high up the astral planes, e.g. Linear B,
+/// Cuneiform, or the CJK Extension blocks) is missing a representative.
+const SCAN_LIMIT: u32 = 0x11_0000;
+
+/// Classify `c`'s script, or `None` if it is `Common`/`Inherited`/`Unknown`
+/// -- a code point (digits, punctuation, whitespace, combining marks) with
+/// no script identity of its own, which shouldn't count toward the
+/// dominant-script tally.
+fn classify_script(c: char) -> Option<Script> {
+    match c.script() {
+        Script::Common | Script::Inherited | Script::Unknown => None,
+        script => Some(script),
+    }
+}
+
+/// `c`'s UTS #39 confusable skeleton, as a `String` (the skeleton of a
+/// single code point is occasionally more than one character, e.g. via NFD
+/// decomposition inside `skeleton`).
+fn skeleton_of(c: char) -> String {
+    let mut buf = [0u8; 4];
+    skeleton(c.encode_utf8(&mut buf)).collect()
+}
+
+/// Reverse index from a UTS #39 skeleton to one representative code point
+/// per script that collapses to it -- e.g. the skeleton `"a"` maps to
+/// `{Latin: 'a', Cyrillic: 'а', Greek: 'α', ...}`. Built once, by brute
+/// force, from the real `confusables.txt`-backed [`skeleton`] function
+/// rather than a hand-typed lookalike table, so it covers every script and
+/// block (digits, fullwidth forms, mathematical alphanumerics, ...) the
+/// Unicode Consortium tracks as confusable.
+fn skeleton_index() -> &'static HashMap<String, HashMap<Script, char>> {
+    static INDEX: OnceLock<HashMap<String, HashMap<Script, char>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index: HashMap<String, HashMap<Script, char>> = HashMap::new();
+        for codepoint in 0..SCAN_LIMIT {
+            let Some(c) = char::from_u32(codepoint) else {
+                continue;
+            };
+            let Some(script) = classify_script(c) else {
+                continue;
+            };
+            index
+                .entry(skeleton_of(c))
+                .or_default()
+                .entry(script)
+                .or_insert(c);
+        }
+        index
+    })
+}
+
+/// Look up the code point that plays `c`'s role in `script`, per the
+/// confusable equivalence class `c` belongs to (its UTS #39 skeleton).
+/// `None` if `script` has no member of that class in [`skeleton_index`].
+fn confusable_in_script(c: char, script: Script) -> Option<char> {
+    skeleton_index().get(&skeleton_of(c))?.get(&script).copied()
+}
+
+/// Canonicalize `text` by mapping out-of-script homoglyphs to their
+/// confusable counterpart *in the dominant script*, per Unicode's
+/// `confusables.txt` mapping.
+///
+/// Tallies [`classify_script`] over every code point (script-less code
+/// points don't count), picks the most frequent script as dominant, then
+/// replaces each code point whose script differs from the dominant one
+/// with the dominant-script member of its confusable equivalence class.
+/// Folding to the *dominant script's* representative -- not to the
+/// skeleton's own fixed prototype, which is frequently Latin regardless of
+/// the surrounding text -- is what keeps an all-Cyrillic (or all-Greek,
+/// all-Armenian, ...) document with one spoofed Latin character hashing
+/// identically to its clean Cyrillic original. Characters with no
+/// dominant-script member in their equivalence class are left untouched.
+pub fn canonicalize_confusables(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut tally: HashMap<Script, usize> = HashMap::new();
+    for &c in &chars {
+        if let Some(script) = classify_script(c) {
+            *tally.entry(script).or_insert(0) += 1;
+        }
+    }
+
+    let dominant = match tally.into_iter().max_by_key(|&(_, count)| count) {
+        Some((script, _)) => script,
+        None => return text.to_string(),
+    };
+
+    chars
+        .into_iter()
+        .map(|c| match classify_script(c) {
+            // Script-less code points (digits, punctuation, whitespace,
+            // combining marks) aren't spoofing anything -- leave them alone.
+            None => c,
+            Some(script) if script == dominant => c,
+            Some(_) => confusable_in_script(c, dominant).unwrap_or(c),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folds_cyrillic_homoglyph_in_latin_text() {
+        // "pаypal" with a Cyrillic "а" in place of Latin "a"
+        let spoofed = "p\u{0430}ypal";
+        assert_eq!(canonicalize_confusables(spoofed), "paypal");
+    }
+
+    #[test]
+    fn test_folds_greek_homoglyph_in_latin_text() {
+        // "Α" is Greek capital alpha, confusable with Latin "A"
+        let spoofed = "\u{0391}pple";
+        assert_eq!(canonicalize_confusables(spoofed), "Apple");
+    }
+
+    #[test]
+    fn test_leaves_pure_script_text_untouched() {
+        let text = "policy-document-42";
+        assert_eq!(canonicalize_confusables(text), text);
+    }
+
+    #[test]
+    fn test_folds_latin_homoglyph_in_cyrillic_dominant_text() {
+        // "привет" (Cyrillic) with a Latin "a" spoofing the final "а".
+        let spoofed = "приветa";
+        assert_eq!(canonicalize_confusables(spoofed), "привета");
+    }
+}
@@ -0,0 +1,93 @@
+// Streaming and file-backed BLAKE3 hashing for large policy packages,
+// so callers aren't forced to materialize multi-gigabyte archives in
+// memory the way the single-shot `hash_blake3` function does.
+
+// `#[pymethods]` expands to an `impl` nested inside a hidden function,
+// which trips the `non_local_definitions` lint on current rustc; this is
+// a known pyo3 0.20 limitation, not a real non-local impl.
+#![allow(non_local_definitions)]
+
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::prelude::*;
+
+/// Persistent BLAKE3 hasher: feed it chunks with `update()` instead of
+/// buffering the whole input up front, then read the digest with
+/// `finalize()`.
+#[pyclass]
+pub struct Blake3Hasher {
+    inner: blake3::Hasher,
+}
+
+#[pymethods]
+impl Blake3Hasher {
+    /// Create a hasher, optionally in keyed mode with a 32-byte `key`
+    /// (mirrors [`hash_blake3`] vs. [`hash_blake3_keyed`]).
+    #[new]
+    #[pyo3(signature = (key=None))]
+    fn new(key: Option<&[u8]>) -> PyResult<Self> {
+        let inner = match key {
+            None => blake3::Hasher::new(),
+            Some(key) => {
+                let key_array: [u8; 32] = key
+                    .try_into()
+                    .map_err(|_| PyValueError::new_err("Key must be exactly 32 bytes"))?;
+                blake3::Hasher::new_keyed(&key_array)
+            }
+        };
+
+        Ok(Self { inner })
+    }
+
+    /// Feed another chunk of data into the hasher.
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Finalize and return the hex-encoded digest without consuming the hasher.
+    fn finalize(&self) -> String {
+        self.inner.finalize().to_hex().to_string()
+    }
+
+    /// Finalize a hasher constructed with a key.
+    ///
+    /// BLAKE3's key binds into the chaining value from the first block, not
+    /// at finalization, so this produces the same digest as `finalize()` --
+    /// it exists for symmetry with `hash_blake3`/`hash_blake3_keyed` and to
+    /// make keyed-mode call sites self-documenting.
+    fn finalize_keyed(&self) -> String {
+        self.finalize()
+    }
+}
+
+/// Hash the file at `path` with BLAKE3, memory-mapping it and hashing with
+/// multiple threads via `update_mmap_rayon` so large policy archives never
+/// need to be fully read into memory.
+pub fn hash_file(path: &str) -> PyResult<String> {
+    let mut hasher = blake3::Hasher::new();
+    hasher
+        .update_mmap_rayon(path)
+        .map_err(|e| PyIOError::new_err(e.to_string()))?;
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("spc_ingestion_{name}_{}.bin", std::process::id()))
+    }
+
+    #[test]
+    fn test_hash_file_matches_direct_blake3_hash() {
+        let path = temp_path("hash_file");
+        let contents = b"policy archive contents".repeat(1024);
+        std::fs::write(&path, &contents).unwrap();
+
+        let mmap_hash = hash_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(mmap_hash, blake3::hash(&contents).to_hex().to_string());
+    }
+}
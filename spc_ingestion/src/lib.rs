@@ -5,10 +5,18 @@
 // - Unicode normalization
 // - Arrow IPC operations
 
-use blake3;
+mod arrow_ipc;
+mod confusables;
+mod hashing;
+mod merkle;
+mod slug;
+
+use std::collections::HashMap;
+
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
-use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
 
 /// Hash binary data with BLAKE3
 #[pyfunction]
@@ -52,22 +60,217 @@ fn normalize_unicode_nfd(py: Python, text: &str) -> PyResult<PyObject> {
     Ok(normalized.into_py(py))
 }
 
-/// Compute Merkle root from sorted hashes
+/// Normalize text to Unicode NFKC (compatibility composition)
 #[pyfunction]
-fn compute_merkle_root(py: Python, hashes: Vec<String>) -> PyResult<PyObject> {
-    if hashes.is_empty() {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Hash list cannot be empty"
-        ));
+fn normalize_unicode_nfkc(py: Python, text: &str) -> PyResult<PyObject> {
+    let normalized: String = text.nfkc().collect();
+    Ok(normalized.into_py(py))
+}
+
+/// Normalize text to Unicode NFKD (compatibility decomposition)
+#[pyfunction]
+fn normalize_unicode_nfkd(py: Python, text: &str) -> PyResult<PyObject> {
+    let normalized: String = text.nfkd().collect();
+    Ok(normalized.into_py(py))
+}
+
+/// Normalize `text` to the named Unicode form, or an error naming the form.
+fn normalize_form(text: &str, form: &str) -> Result<String, String> {
+    match form {
+        "NFC" => Ok(text.nfc().collect()),
+        "NFD" => Ok(text.nfd().collect()),
+        "NFKC" => Ok(text.nfkc().collect()),
+        "NFKD" => Ok(text.nfkd().collect()),
+        other => Err(format!("Unknown normalization form: {other}")),
     }
-    
-    let mut sorted_hashes = hashes.clone();
-    sorted_hashes.sort();
-    
-    let combined = sorted_hashes.join("");
-    let root_hash = blake3::hash(combined.as_bytes());
-    
-    Ok(root_hash.to_hex().to_string().into_py(py))
+}
+
+/// Normalize text to the named Unicode form ("NFC", "NFD", "NFKC", "NFKD").
+///
+/// Lets callers pin a single canonical form (e.g. for hashing) without
+/// branching on the individual `normalize_unicode_*` functions themselves.
+#[pyfunction]
+fn normalize(py: Python, text: &str, form: &str) -> PyResult<PyObject> {
+    let normalized =
+        normalize_form(text, form).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    Ok(normalized.into_py(py))
+}
+
+/// Quick-check whether text is already in Unicode NFC form (UAX #15).
+///
+/// Falls back to a full normalize-and-compare only when the quick-check
+/// is inconclusive (`Maybe`), so already-canonical policy text takes a
+/// near-constant-time path instead of paying for a full normalization.
+#[pyfunction]
+fn is_normalized_nfc(py: Python, text: &str) -> PyResult<PyObject> {
+    let is_normalized = match is_nfc_quick(text.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => {
+            let normalized: String = text.nfc().collect();
+            normalized == text
+        }
+    };
+
+    Ok(is_normalized.into_py(py))
+}
+
+/// Quick-check whether text is already in Unicode NFD form (UAX #15).
+///
+/// See [`is_normalized_nfc`] for the quick-check/fallback strategy.
+#[pyfunction]
+fn is_normalized_nfd(py: Python, text: &str) -> PyResult<PyObject> {
+    let is_normalized = match is_nfd_quick(text.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => {
+            let normalized: String = text.nfd().collect();
+            normalized == text
+        }
+    };
+
+    Ok(is_normalized.into_py(py))
+}
+
+/// Quick-check whether text is already in Unicode NFKC form (UAX #15).
+///
+/// See [`is_normalized_nfc`] for the quick-check/fallback strategy.
+#[pyfunction]
+fn is_normalized_nfkc(py: Python, text: &str) -> PyResult<PyObject> {
+    let is_normalized = match is_nfkc_quick(text.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => {
+            let normalized: String = text.nfkc().collect();
+            normalized == text
+        }
+    };
+
+    Ok(is_normalized.into_py(py))
+}
+
+/// Quick-check whether text is already in Unicode NFKD form (UAX #15).
+///
+/// See [`is_normalized_nfc`] for the quick-check/fallback strategy.
+#[pyfunction]
+fn is_normalized_nfkd(py: Python, text: &str) -> PyResult<PyObject> {
+    let is_normalized = match is_nfkd_quick(text.chars()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => {
+            let normalized: String = text.nfkd().collect();
+            normalized == text
+        }
+    };
+
+    Ok(is_normalized.into_py(py))
+}
+
+/// Fold out-of-script homoglyphs (e.g. Cyrillic "а" in Latin text) to their
+/// confusable counterpart in the string's dominant script.
+///
+/// Guards canonical BLAKE3 hashing against spoofing where a policy document
+/// is visually identical but hashes differently because of a substituted
+/// look-alike character.
+#[pyfunction]
+fn canonicalize_confusables(py: Python, text: &str) -> PyResult<PyObject> {
+    Ok(confusables::canonicalize_confusables(text).into_py(py))
+}
+
+/// Compute the root of a binary Merkle tree over `leaves`, in the given order.
+///
+/// Leaves are hashed as `BLAKE3(0x00 || leaf)` and internal nodes as
+/// `BLAKE3(0x01 || left || right)`, with the last node of an odd-length
+/// level duplicated to pair up. Domain-separating leaves from internal
+/// nodes prevents a leaf hash from being replayed as an internal node
+/// (second-preimage attack). Returns the 32-byte root as a hex string.
+#[pyfunction]
+fn compute_merkle_root(py: Python, leaves: Vec<Vec<u8>>) -> PyResult<PyObject> {
+    let root =
+        merkle::compute_root(&leaves).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    Ok(blake3::Hash::from(root).to_hex().to_string().into_py(py))
+}
+
+/// Build the inclusion proof for the leaf at `index`.
+///
+/// Returns a list of `(sibling_hash_hex, sibling_is_right)` pairs, ordered
+/// from the leaf's level up to the root, for use with
+/// [`verify_merkle_proof`].
+#[pyfunction]
+fn merkle_proof(py: Python, leaves: Vec<Vec<u8>>, index: usize) -> PyResult<PyObject> {
+    let steps =
+        merkle::proof(&leaves, index).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+    let proof: Vec<(String, bool)> = steps
+        .into_iter()
+        .map(|step| {
+            (
+                blake3::Hash::from(step.sibling).to_hex().to_string(),
+                step.sibling_is_right,
+            )
+        })
+        .collect();
+
+    Ok(proof.into_py(py))
+}
+
+/// Verify that `leaf` is included in the tree whose root is `root_hex`,
+/// given the `proof` returned by [`merkle_proof`].
+#[pyfunction]
+fn verify_merkle_proof(
+    py: Python,
+    leaf: Vec<u8>,
+    proof: Vec<(String, bool)>,
+    root_hex: &str,
+) -> PyResult<PyObject> {
+    let root: [u8; 32] = *blake3::Hash::from_hex(root_hex)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid root hex: {e}")))?
+        .as_bytes();
+
+    let steps = proof
+        .into_iter()
+        .map(|(sibling_hex, sibling_is_right)| {
+            let sibling: [u8; 32] = *blake3::Hash::from_hex(&sibling_hex)
+                .map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Invalid sibling hex: {e}"))
+                })?
+                .as_bytes();
+            Ok(merkle::ProofStep {
+                sibling,
+                sibling_is_right,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok(merkle::verify(&leaf, &steps, &root).into_py(py))
+}
+
+/// NFKD-decompose `text`, drop combining marks, and keep only the
+/// remaining ASCII code points.
+#[pyfunction]
+fn fold_to_ascii(py: Python, text: &str) -> PyResult<PyObject> {
+    Ok(slug::fold_to_ascii(text).into_py(py))
+}
+
+/// Derive a deterministic, filesystem-safe slug from `text`: ASCII-fold,
+/// lowercase, collapse runs of non-alphanumeric characters into a single
+/// separator, and trim leading/trailing separators.
+///
+/// Gives the ingestion layer a reproducible mapping from arbitrary
+/// Unicode policy titles to ASCII keys usable as file paths and
+/// dictionary keys.
+#[pyfunction]
+fn slugify(py: Python, text: &str) -> PyResult<PyObject> {
+    Ok(slug::slugify(text).into_py(py))
+}
+
+/// Hash the file at `path` with BLAKE3, using a memory-mapped, multithreaded
+/// read so large policy archives don't need to be loaded into memory first.
+#[pyfunction]
+fn hash_file(py: Python, path: &str) -> PyResult<PyObject> {
+    Ok(hashing::hash_file(path)?.into_py(py))
 }
 
 /// Segment text into grapheme clusters (for stable tokenization)
@@ -83,6 +286,41 @@ fn segment_graphemes(py: Python, text: &str) -> PyResult<PyObject> {
     Ok(graphemes.into_py(py))
 }
 
+/// Write `columns` (one list of strings per column: "id", "normalized_text",
+/// "blake3_hash") to `path` as a single-batch Arrow IPC file.
+///
+/// Delivers the zero-copy, language-interoperable columnar storage format
+/// the module header promises: a canonical on-disk layout for policy rows
+/// that other languages/processes can read without re-parsing.
+#[pyfunction]
+fn write_arrow_ipc(
+    py: Python,
+    columns: HashMap<String, Vec<String>>,
+    path: &str,
+) -> PyResult<PyObject> {
+    arrow_ipc::write_arrow_ipc(&columns, path)
+        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(py.None())
+}
+
+/// Read the policy-row record batch back from the Arrow IPC file at `path`,
+/// returning a dict of column name to list of values.
+#[pyfunction]
+fn read_arrow_ipc(py: Python, path: &str) -> PyResult<PyObject> {
+    let columns =
+        arrow_ipc::read_arrow_ipc(path).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(columns.into_py(py))
+}
+
+/// Compute a canonical BLAKE3 digest over the Arrow IPC bytes at `path`, so
+/// the same columnar dataset always hashes identically.
+#[pyfunction]
+fn hash_arrow_batch(py: Python, path: &str) -> PyResult<PyObject> {
+    let hash =
+        arrow_ipc::hash_arrow_batch(path).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    Ok(hash.into_py(py))
+}
+
 /// Python module
 #[pymodule]
 fn spc_ingestion(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -90,8 +328,25 @@ fn spc_ingestion(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(hash_blake3_keyed, m)?)?;
     m.add_function(wrap_pyfunction!(normalize_unicode_nfc, m)?)?;
     m.add_function(wrap_pyfunction!(normalize_unicode_nfd, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_unicode_nfkc, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_unicode_nfkd, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize, m)?)?;
+    m.add_function(wrap_pyfunction!(is_normalized_nfc, m)?)?;
+    m.add_function(wrap_pyfunction!(is_normalized_nfd, m)?)?;
+    m.add_function(wrap_pyfunction!(is_normalized_nfkc, m)?)?;
+    m.add_function(wrap_pyfunction!(is_normalized_nfkd, m)?)?;
+    m.add_function(wrap_pyfunction!(canonicalize_confusables, m)?)?;
     m.add_function(wrap_pyfunction!(compute_merkle_root, m)?)?;
+    m.add_function(wrap_pyfunction!(merkle_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_merkle_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(fold_to_ascii, m)?)?;
+    m.add_function(wrap_pyfunction!(slugify, m)?)?;
+    m.add_function(wrap_pyfunction!(hash_file, m)?)?;
     m.add_function(wrap_pyfunction!(segment_graphemes, m)?)?;
+    m.add_function(wrap_pyfunction!(write_arrow_ipc, m)?)?;
+    m.add_function(wrap_pyfunction!(read_arrow_ipc, m)?)?;
+    m.add_function(wrap_pyfunction!(hash_arrow_batch, m)?)?;
+    m.add_class::<hashing::Blake3Hasher>()?;
 
     // Backward compatibility alias
     m.add("__legacy_name__", "cpp_ingestion")?;
@@ -118,4 +373,40 @@ mod tests {
         
         assert_ne!(nfc.len(), nfd.len()); // Decomposed form has more chars
     }
+
+    #[test]
+    fn test_is_normalized_quick_check() {
+        let already_nfc = "cafe";
+        assert_eq!(is_nfc_quick(already_nfc.chars()), IsNormalized::Yes);
+
+        let decomposed = "cafe\u{0301}"; // "café" with a combining acute accent
+        assert_ne!(is_nfc_quick(decomposed.chars()), IsNormalized::Yes);
+    }
+
+    #[test]
+    fn test_is_normalized_nfkc_quick_check() {
+        let already_nfkc = "cafe";
+        assert_eq!(is_nfkc_quick(already_nfkc.chars()), IsNormalized::Yes);
+
+        // Roman numeral one compatibility-decomposes to "I" under NFKC.
+        let compatibility_equivalent = "\u{2160}";
+        assert_ne!(
+            is_nfkc_quick(compatibility_equivalent.chars()),
+            IsNormalized::Yes
+        );
+    }
+
+    #[test]
+    fn test_normalize_dispatcher_matches_direct_calls() {
+        let text = "\u{2160}"; // Roman numeral one, compatibility-decomposes to "I"
+
+        let nfkc: String = text.nfkc().collect();
+        let via_dispatcher = normalize_form(text, "NFKC").unwrap();
+        assert_eq!(nfkc, via_dispatcher);
+    }
+
+    #[test]
+    fn test_normalize_dispatcher_rejects_unknown_form() {
+        assert!(normalize_form("abc", "NFKQ").is_err());
+    }
 }
@@ -0,0 +1,144 @@
+// Binary Merkle tree construction with domain-separated leaves/nodes and
+// inclusion proofs.
+//
+// Leaves and internal nodes are hashed with distinct prefixes so a leaf
+// hash can never be replayed as an internal node hash (the classic second-
+// preimage attack against naive Merkle trees).
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+fn hash_leaf(leaf_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(leaf_bytes);
+    *hasher.finalize().as_bytes()
+}
+
+fn hash_internal(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    *hasher.finalize().as_bytes()
+}
+
+/// One step of a Merkle inclusion proof: a sibling hash and which side of
+/// the node being folded up it sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofStep {
+    pub sibling: [u8; 32],
+    pub sibling_is_right: bool,
+}
+
+/// Build every level of the tree bottom-up, duplicating the last node of
+/// an odd-length level so every level pairs up cleanly.
+fn build_levels(leaves: &[Vec<u8>]) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves.iter().map(|leaf| hash_leaf(leaf)).collect::<Vec<_>>()];
+
+    while levels.last().expect("levels is never empty").len() > 1 {
+        let prev = levels.last().expect("checked non-empty above");
+        let mut next = Vec::with_capacity(prev.len().div_ceil(2));
+        let mut i = 0;
+        while i < prev.len() {
+            let left = prev[i];
+            let right = prev.get(i + 1).copied().unwrap_or(left);
+            next.push(hash_internal(&left, &right));
+            i += 2;
+        }
+        levels.push(next);
+    }
+
+    levels
+}
+
+/// Compute the root of the Merkle tree over `leaves`, in the given order.
+pub fn compute_root(leaves: &[Vec<u8>]) -> Result<[u8; 32], String> {
+    if leaves.is_empty() {
+        return Err("Leaf list cannot be empty".to_string());
+    }
+
+    let levels = build_levels(leaves);
+    Ok(*levels
+        .last()
+        .expect("levels is never empty")
+        .first()
+        .expect("top level always has exactly one node"))
+}
+
+/// Build the inclusion proof for the leaf at `index`: the ordered sibling
+/// hashes needed to recompute the root, each tagged with which side it
+/// sits on.
+pub fn proof(leaves: &[Vec<u8>], index: usize) -> Result<Vec<ProofStep>, String> {
+    if index >= leaves.len() {
+        return Err(format!(
+            "Index {index} out of bounds for {} leaves",
+            leaves.len()
+        ));
+    }
+
+    let levels = build_levels(leaves);
+    let mut steps = Vec::with_capacity(levels.len() - 1);
+    let mut idx = index;
+
+    for level in &levels[..levels.len() - 1] {
+        let is_left = idx.is_multiple_of(2);
+        let sibling_idx = if is_left { idx + 1 } else { idx - 1 };
+        let sibling_idx = sibling_idx.min(level.len() - 1);
+
+        steps.push(ProofStep {
+            sibling: level[sibling_idx],
+            sibling_is_right: is_left,
+        });
+        idx /= 2;
+    }
+
+    Ok(steps)
+}
+
+/// Recompute the root from `leaf` and its proof, and check it matches `root`.
+pub fn verify(leaf: &[u8], steps: &[ProofStep], root: &[u8; 32]) -> bool {
+    let folded = steps.iter().fold(hash_leaf(leaf), |acc, step| {
+        if step.sibling_is_right {
+            hash_internal(&acc, &step.sibling)
+        } else {
+            hash_internal(&step.sibling, &acc)
+        }
+    });
+
+    &folded == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_is_deterministic_and_domain_separated_from_leaves() {
+        let leaves = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        let root = compute_root(&leaves).unwrap();
+
+        assert_ne!(root, hash_leaf(b"a"));
+        assert_eq!(root, compute_root(&leaves).unwrap());
+    }
+
+    #[test]
+    fn test_proof_verifies_for_every_leaf_including_odd_count() {
+        let leaves = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        let root = compute_root(&leaves).unwrap();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let steps = proof(&leaves, i).unwrap();
+            assert!(verify(leaf, &steps, &root));
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_leaf() {
+        let leaves = vec![b"a".to_vec(), b"b".to_vec()];
+        let root = compute_root(&leaves).unwrap();
+        let steps = proof(&leaves, 0).unwrap();
+
+        assert!(!verify(b"not-a", &steps, &root));
+    }
+}
@@ -0,0 +1,59 @@
+// ASCII-folding and slug generation for deterministic, filesystem-safe
+// policy identifiers derived from arbitrary Unicode titles.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// NFKD-decompose `text`, drop combining marks, and keep only the
+/// remaining ASCII code points.
+pub fn fold_to_ascii(text: &str) -> String {
+    text.nfkd()
+        .filter(|&c| unicode_normalization::char::canonical_combining_class(c) == 0)
+        .filter(char::is_ascii)
+        .collect()
+}
+
+/// Derive a stable, filesystem-safe slug from `text`: ASCII-fold, lowercase,
+/// collapse runs of non-alphanumeric characters into a single `-`, and trim
+/// leading/trailing separators.
+pub fn slugify(text: &str) -> String {
+    let folded = fold_to_ascii(text).to_lowercase();
+
+    let mut slug = String::with_capacity(folded.len());
+    let mut last_was_separator = true; // suppresses a leading separator
+    for c in folded.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+            last_was_separator = false;
+        } else if !last_was_separator {
+            slug.push('-');
+            last_was_separator = true;
+        }
+    }
+
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_to_ascii_drops_accents() {
+        assert_eq!(fold_to_ascii("café policy"), "cafe policy");
+    }
+
+    #[test]
+    fn test_slugify_collapses_and_trims_separators() {
+        assert_eq!(slugify("  Café Policy -- v2!!  "), "cafe-policy-v2");
+    }
+
+    #[test]
+    fn test_slugify_is_stable_across_equivalent_unicode_titles() {
+        // "é" as a precomposed char vs. "e" + combining acute accent.
+        assert_eq!(slugify("Caf\u{00E9}"), slugify("Cafe\u{0301}"));
+    }
+}